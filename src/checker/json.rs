@@ -0,0 +1,140 @@
+//! Structural JSON output checker (`out_check: json`).
+//!
+//! Parses both the expected and the produced output as [`serde_json::Value`]
+//! and compares them structurally rather than textually: object key order is
+//! ignored, array order matters, numbers are compared by value within a
+//! tolerance, and whitespace/indentation never affects the verdict.
+
+use serde_json::Value;
+
+use super::{CheckFailure, Checker};
+
+/// Structural JSON comparison with a numeric tolerance.
+///
+/// `tolerance` bounds the absolute difference allowed between two JSON
+/// numbers that are otherwise in corresponding positions (e.g. `1` vs
+/// `1.0`, or `0.30000000000000004` vs `0.3`). A tolerance of `0.0` requires
+/// numbers to compare exactly equal as `f64`.
+pub struct JsonChecker {
+    tolerance: f64,
+}
+
+impl JsonChecker {
+    pub fn with_tolerance(tolerance: f64) -> Self {
+        JsonChecker { tolerance }
+    }
+}
+
+/// The default instance used for plain `out_check: json`, with no numeric
+/// tolerance.
+impl Default for JsonChecker {
+    fn default() -> Self {
+        JsonChecker { tolerance: 0.0 }
+    }
+}
+
+impl Checker for JsonChecker {
+    fn check(&self, expected: &str, produced: &str) -> Result<(), CheckFailure> {
+        let expected: Value = serde_json::from_str(expected).map_err(|e| CheckFailure {
+            message: format!("expected output is not valid JSON: {e}"),
+        })?;
+        let produced: Value = serde_json::from_str(produced).map_err(|e| CheckFailure {
+            message: format!("produced output is not valid JSON: {e}"),
+        })?;
+
+        match diff(&expected, &produced, "$", self.tolerance) {
+            Some(path) => Err(CheckFailure {
+                message: format!("JSON outputs differ at {path}"),
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Returns the JSON path of the first structural difference between `a` and
+/// `b`, or `None` if they're equivalent.
+fn diff(a: &Value, b: &Value, path: &str, tolerance: f64) -> Option<String> {
+    match (a, b) {
+        (Value::Object(a), Value::Object(b)) => {
+            if a.len() != b.len() {
+                return Some(path.to_string());
+            }
+            for (key, a_val) in a {
+                let Some(b_val) = b.get(key) else {
+                    return Some(format!("{path}.{key}"));
+                };
+                if let Some(mismatch) = diff(a_val, b_val, &format!("{path}.{key}"), tolerance) {
+                    return Some(mismatch);
+                }
+            }
+            None
+        }
+        (Value::Array(a), Value::Array(b)) => {
+            if a.len() != b.len() {
+                return Some(path.to_string());
+            }
+            a.iter().zip(b.iter()).enumerate().find_map(|(i, (a, b))| {
+                diff(a, b, &format!("{path}[{i}]"), tolerance)
+            })
+        }
+        (Value::Number(a), Value::Number(b)) => {
+            let (Some(a), Some(b)) = (a.as_f64(), b.as_f64()) else {
+                return Some(path.to_string());
+            };
+            if (a - b).abs() <= tolerance {
+                None
+            } else {
+                Some(path.to_string())
+            }
+        }
+        _ if a == b => None,
+        _ => Some(path.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_differently_ordered_keys_and_formatting() {
+        let checker = JsonChecker::default();
+        let expected = r#"{"a": 1, "b": [1, 2, 3]}"#;
+        let produced = "{\n  \"b\": [1, 2, 3],\n  \"a\": 1\n}\n";
+        assert!(checker.check(expected, produced).is_ok());
+    }
+
+    #[test]
+    fn array_order_matters() {
+        let checker = JsonChecker::default();
+        let expected = "[1, 2, 3]";
+        let produced = "[3, 2, 1]";
+        assert!(checker.check(expected, produced).is_err());
+    }
+
+    #[test]
+    fn numbers_within_tolerance_are_equal() {
+        let checker = JsonChecker::with_tolerance(1e-6);
+        assert!(checker.check("1.0", "1").is_ok());
+        assert!(checker.check("0.3", "0.30000000000000004").is_ok());
+    }
+
+    #[test]
+    fn numbers_outside_tolerance_are_rejected() {
+        let checker = JsonChecker::with_tolerance(0.01);
+        assert!(checker.check("1.0", "1.1").is_err());
+    }
+
+    #[test]
+    fn reports_the_first_differing_path() {
+        let checker = JsonChecker::default();
+        let expected = r#"{"results": [{"score": 1}, {"score": 2}, {"score": 3}]}"#;
+        let produced = r#"{"results": [{"score": 1}, {"score": 2}, {"score": 4}]}"#;
+        let err = checker.check(expected, produced).unwrap_err();
+        assert!(
+            err.message.contains("$.results[2].score"),
+            "unexpected message: {}",
+            err.message
+        );
+    }
+}
@@ -0,0 +1,67 @@
+//! Output checkers: pluggable strategies for comparing a produced output
+//! against the expected one, selected per task via `out_check` in the task
+//! config.
+
+mod json;
+
+pub use json::JsonChecker;
+
+use std::fmt;
+
+/// The `out_check` modes a task config can select.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CheckMode {
+    /// Exact byte-for-byte comparison (pisek's historical default).
+    Bytes,
+    /// Whitespace-insensitive, token-by-token comparison.
+    Tokens,
+    /// Structural comparison of two JSON documents (see [`JsonChecker`]),
+    /// with numbers considered equal within `tolerance` of each other.
+    Json { tolerance: f64 },
+}
+
+/// Why a checker rejected a solution's output.
+#[derive(Debug)]
+pub struct CheckFailure {
+    pub message: String,
+}
+
+impl fmt::Display for CheckFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for CheckFailure {}
+
+/// A strategy for comparing produced output against the expected output.
+pub trait Checker {
+    /// Returns `Ok(())` if `produced` is accepted as equivalent to
+    /// `expected`, otherwise a [`CheckFailure`] describing the mismatch.
+    fn check(&self, expected: &str, produced: &str) -> Result<(), CheckFailure>;
+}
+
+/// Dispatches to the checker implementation for `mode`.
+pub fn check(mode: CheckMode, expected: &str, produced: &str) -> Result<(), CheckFailure> {
+    match mode {
+        CheckMode::Bytes => {
+            if expected == produced {
+                Ok(())
+            } else {
+                Err(CheckFailure {
+                    message: "outputs differ byte-for-byte".to_string(),
+                })
+            }
+        }
+        CheckMode::Tokens => {
+            if expected.split_whitespace().eq(produced.split_whitespace()) {
+                Ok(())
+            } else {
+                Err(CheckFailure {
+                    message: "outputs differ token-by-token".to_string(),
+                })
+            }
+        }
+        CheckMode::Json { tolerance } => JsonChecker::with_tolerance(tolerance).check(expected, produced),
+    }
+}
@@ -0,0 +1,54 @@
+//! Pluggable execution backends for running a solution against a single
+//! test's stdin and collecting its stdout plus resource usage.
+
+pub mod wasi;
+
+pub use wasi::WasiBackend;
+
+use std::path::Path;
+
+/// Resource limits enforced while a solution runs.
+#[derive(Debug, Clone, Copy)]
+pub struct ExecLimits {
+    pub time_ms: u64,
+    pub mem_kb: u64,
+}
+
+/// The result of running a solution to completion (or until a limit hit).
+#[derive(Debug, Clone)]
+pub struct ExecOutcome {
+    pub stdout: Vec<u8>,
+    pub exit_code: i32,
+    pub time_ms: u64,
+    pub mem_kb: u64,
+    pub timed_out: bool,
+}
+
+#[derive(Debug)]
+pub enum ExecError {
+    Io(std::io::Error),
+    Backend(String),
+}
+
+impl std::fmt::Display for ExecError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExecError::Io(e) => write!(f, "i/o error: {e}"),
+            ExecError::Backend(msg) => write!(f, "{msg}"),
+        }
+    }
+}
+
+impl std::error::Error for ExecError {}
+
+impl From<std::io::Error> for ExecError {
+    fn from(e: std::io::Error) -> Self {
+        ExecError::Io(e)
+    }
+}
+
+/// A way to run a compiled solution against one test case's stdin.
+pub trait ExecBackend {
+    /// Runs `program` with `stdin` piped in, enforcing `limits`.
+    fn run(&self, program: &Path, stdin: &[u8], limits: ExecLimits) -> Result<ExecOutcome, ExecError>;
+}
@@ -0,0 +1,178 @@
+//! Execution backend that runs a solution compiled to `wasm32-wasi` inside
+//! an embedded wasmtime runtime, wiring stdin/stdout through the WASI
+//! `fd_read`/`fd_write` interface exactly as a native program would see
+//! them.
+//!
+//! Compared to running a native subprocess, this gives deterministic,
+//! cross-platform sandboxing: a fuel budget caps computation regardless of
+//! host speed, a [`wasmtime::ResourceLimiter`] caps memory, and the guest
+//! gets no filesystem access beyond an explicitly preopened input
+//! directory.
+
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use wasi_common::pipe::{ReadPipe, WritePipe};
+use wasi_common::sync::WasiCtxBuilder;
+use wasmtime::{
+    Config, Engine, Linker, Module, ResourceLimiter, Store, StoreLimits, StoreLimitsBuilder,
+};
+
+use super::{ExecBackend, ExecError, ExecLimits, ExecOutcome};
+
+/// Fuel consumed per limited unit of host time; calibrated so that a fuel
+/// budget derived from `limits.time_ms` approximates a wall-clock cap
+/// without relying on OS-level timers inside the sandbox.
+const FUEL_PER_MS: u64 = 10_000_000;
+
+/// Wraps [`StoreLimits`] to additionally record the high-water mark of
+/// linear memory actually granted to the guest, so callers can report real
+/// consumption instead of just echoing back the configured cap.
+struct MemTrackingLimiter {
+    limits: StoreLimits,
+    peak_bytes: usize,
+}
+
+impl MemTrackingLimiter {
+    fn new(limits: StoreLimits) -> Self {
+        MemTrackingLimiter {
+            limits,
+            peak_bytes: 0,
+        }
+    }
+}
+
+impl ResourceLimiter for MemTrackingLimiter {
+    fn memory_growing(
+        &mut self,
+        current: usize,
+        desired: usize,
+        maximum: Option<usize>,
+    ) -> wasmtime::Result<bool> {
+        let allowed = self.limits.memory_growing(current, desired, maximum)?;
+        self.peak_bytes = self.peak_bytes.max(if allowed { desired } else { current });
+        Ok(allowed)
+    }
+
+    fn memory_grow_failed(&mut self, error: wasmtime::Error) -> wasmtime::Result<()> {
+        self.limits.memory_grow_failed(error)
+    }
+
+    fn table_growing(
+        &mut self,
+        current: usize,
+        desired: usize,
+        maximum: Option<usize>,
+    ) -> wasmtime::Result<bool> {
+        self.limits.table_growing(current, desired, maximum)
+    }
+
+    fn instances(&self) -> usize {
+        self.limits.instances()
+    }
+
+    fn tables(&self) -> usize {
+        self.limits.tables()
+    }
+
+    fn memories(&self) -> usize {
+        self.limits.memories()
+    }
+}
+
+/// Runs `wasm32-wasi` solution binaries under wasmtime.
+pub struct WasiBackend {
+    engine: Engine,
+    /// Directory granted to the guest as its only visible filesystem,
+    /// typically the directory holding the test's input file.
+    input_dir: PathBuf,
+}
+
+impl WasiBackend {
+    /// Creates a backend whose guests see no filesystem except `input_dir`,
+    /// preopened as `/input`.
+    pub fn new(input_dir: impl Into<PathBuf>) -> Result<Self, ExecError> {
+        let mut config = Config::new();
+        config.consume_fuel(true);
+        let engine = Engine::new(&config).map_err(|e| ExecError::Backend(e.to_string()))?;
+        Ok(WasiBackend {
+            engine,
+            input_dir: input_dir.into(),
+        })
+    }
+}
+
+impl ExecBackend for WasiBackend {
+    fn run(&self, program: &Path, stdin: &[u8], limits: ExecLimits) -> Result<ExecOutcome, ExecError> {
+        let module = Module::from_file(&self.engine, program)
+            .map_err(|e| ExecError::Backend(format!("failed to load wasm module: {e}")))?;
+
+        let stdin_pipe = ReadPipe::from(stdin.to_vec());
+        let stdout_pipe = WritePipe::new_in_memory();
+
+        let wasi_ctx = WasiCtxBuilder::new()
+            .stdin(Box::new(stdin_pipe))
+            .stdout(Box::new(stdout_pipe.clone()))
+            .preopened_dir(
+                cap_std::fs::Dir::open_ambient_dir(&self.input_dir, cap_std::ambient_authority())
+                    .map_err(|e| ExecError::Backend(format!("cannot open input dir: {e}")))?,
+                "/input",
+            )
+            .map_err(|e| ExecError::Backend(e.to_string()))?
+            .build();
+
+        let limiter = MemTrackingLimiter::new(
+            StoreLimitsBuilder::new()
+                .memory_size((limits.mem_kb as usize) * 1024)
+                .build(),
+        );
+
+        let mut store = Store::new(&self.engine, (wasi_ctx, limiter));
+        store.limiter(|(_, limiter): &mut (_, MemTrackingLimiter)| {
+            limiter as &mut dyn ResourceLimiter
+        });
+        store
+            .set_fuel(limits.time_ms.saturating_mul(FUEL_PER_MS))
+            .map_err(|e| ExecError::Backend(e.to_string()))?;
+
+        let mut linker: Linker<(_, MemTrackingLimiter)> = Linker::new(&self.engine);
+        wasi_common::sync::add_to_linker(&mut linker, |(ctx, _)| ctx)
+            .map_err(|e| ExecError::Backend(e.to_string()))?;
+
+        let start = Instant::now();
+        let run_result: wasmtime::Result<()> = (|| {
+            linker.module(&mut store, "", &module)?;
+            let func = linker.get_default(&mut store, "")?;
+            func.typed::<(), ()>(&store)?.call(&mut store, ())
+        })();
+        let time_ms = start.elapsed().as_millis() as u64;
+
+        let timed_out = matches!(
+            &run_result,
+            Err(e) if e.downcast_ref::<wasmtime::Trap>() == Some(&wasmtime::Trap::OutOfFuel)
+        );
+        let exit_code = match &run_result {
+            Ok(()) => 0,
+            Err(e) => match e.downcast_ref::<wasi_common::I32Exit>() {
+                Some(wasi_common::I32Exit(code)) => *code,
+                None => -1,
+            },
+        };
+
+        let (_, limiter) = store.into_data();
+        let mem_kb = (limiter.peak_bytes as u64).div_ceil(1024);
+
+        let stdout = stdout_pipe
+            .try_into_inner()
+            .map_err(|_| ExecError::Backend("stdout pipe still has outstanding references".to_string()))?
+            .into_inner();
+
+        Ok(ExecOutcome {
+            stdout,
+            exit_code,
+            time_ms,
+            mem_kb,
+            timed_out,
+        })
+    }
+}
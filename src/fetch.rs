@@ -0,0 +1,288 @@
+//! Fetching remote test-data sources declared in a task config by URL,
+//! instead of requiring every input/generator/reference byte to live in the
+//! repo.
+//!
+//! Downloads are cached by checksum under a local cache directory, so a
+//! second run against the same source reuses the cached copy even when
+//! offline. To keep the dependency footprint minimal (no TLS crate in the
+//! build), the actual transfer is delegated to a configurable external
+//! command, defaulting to the system `curl`.
+
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use sha2::{Digest, Sha256};
+
+/// A single remote test-data source declared in a task config.
+#[derive(Debug, Clone)]
+pub struct FetchSource {
+    pub url: String,
+    /// Expected SHA-256 of the downloaded payload, as a lowercase hex
+    /// string.
+    pub sha256: String,
+}
+
+#[derive(Debug)]
+pub enum FetchError {
+    Command(std::io::Error),
+    /// The fetch command exited non-zero or the HTTP status wasn't 2xx.
+    CommandFailed { status: String },
+    ChecksumMismatch { expected: String, actual: String },
+    /// `sha256` isn't a 64-character hex digest, so it's unsafe to use as a
+    /// cache path component.
+    InvalidChecksum { sha256: String },
+    /// `with_command` was given an empty argument list, so there's no
+    /// program to run.
+    EmptyCommand,
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::Command(e) => write!(f, "failed to run fetch command: {e}"),
+            FetchError::CommandFailed { status } => write!(f, "fetch command failed: {status}"),
+            FetchError::ChecksumMismatch { expected, actual } => write!(
+                f,
+                "checksum mismatch: expected {expected}, got {actual}"
+            ),
+            FetchError::InvalidChecksum { sha256 } => {
+                write!(f, "sha256 {sha256:?} is not a 64-character hex digest")
+            }
+            FetchError::EmptyCommand => write!(f, "fetch command is empty"),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+/// Where fetched files are cached and how they're downloaded.
+pub struct FetchCache {
+    cache_dir: PathBuf,
+    /// The fetch command, defaulting to `curl -fsSL -o <output> <url>`.
+    /// `-f` makes curl report non-2xx HTTP statuses as a failure exit code,
+    /// since pisek never parses curl's own output.
+    command: Vec<String>,
+}
+
+impl FetchCache {
+    pub fn new(cache_dir: impl Into<PathBuf>) -> Self {
+        FetchCache {
+            cache_dir: cache_dir.into(),
+            command: vec![
+                "curl".to_string(),
+                "-fsSL".to_string(),
+                "-o".to_string(),
+                "{output}".to_string(),
+                "{url}".to_string(),
+            ],
+        }
+    }
+
+    /// Overrides the default `curl` invocation, e.g. to add proxy flags or
+    /// swap in a different downloader. `{url}` and `{output}` are
+    /// substituted into the argument list before the command runs.
+    pub fn with_command(mut self, command: Vec<String>) -> Self {
+        self.command = command;
+        self
+    }
+
+    /// Returns the local path for `source`, fetching it first if it isn't
+    /// already cached. The cache key is the expected checksum, so a source
+    /// whose URL changes but whose content doesn't is still reused, and a
+    /// corrupted cache entry can never be served silently.
+    pub fn resolve(&self, source: &FetchSource) -> Result<PathBuf, FetchError> {
+        if !is_valid_sha256(&source.sha256) {
+            return Err(FetchError::InvalidChecksum {
+                sha256: source.sha256.clone(),
+            });
+        }
+
+        let cached_path = self.cache_dir.join(&source.sha256);
+        if cached_path.is_file() {
+            if let Ok(actual) = sha256_file(&cached_path) {
+                if actual == source.sha256 {
+                    return Ok(cached_path);
+                }
+            }
+        }
+
+        fs::create_dir_all(&self.cache_dir).map_err(FetchError::Command)?;
+        let tmp_path = self.cache_dir.join(format!("{}.part", source.sha256));
+
+        let args: Vec<String> = self
+            .command
+            .iter()
+            .map(|arg| {
+                arg.replace("{url}", &source.url)
+                    .replace("{output}", &tmp_path.to_string_lossy())
+            })
+            .collect();
+        let (program, rest) = args.split_first().ok_or(FetchError::EmptyCommand)?;
+        let status = Command::new(program)
+            .args(rest)
+            .status()
+            .map_err(FetchError::Command)?;
+        if !status.success() {
+            let _ = fs::remove_file(&tmp_path);
+            return Err(FetchError::CommandFailed {
+                status: status.to_string(),
+            });
+        }
+
+        let actual = sha256_file(&tmp_path).map_err(FetchError::Command)?;
+        if actual != source.sha256 {
+            let _ = fs::remove_file(&tmp_path);
+            return Err(FetchError::ChecksumMismatch {
+                expected: source.sha256.clone(),
+                actual,
+            });
+        }
+
+        fs::rename(&tmp_path, &cached_path).map_err(FetchError::Command)?;
+        Ok(cached_path)
+    }
+}
+
+/// Whether `sha256` is a well-formed 64-character lowercase hex digest,
+/// i.e. safe to use as a single path component (no `/`, `..`, or absolute
+/// paths that would let `PathBuf::join` escape `cache_dir`).
+fn is_valid_sha256(sha256: &str) -> bool {
+    sha256.len() == 64 && sha256.bytes().all(|b| b.is_ascii_hexdigit() && !b.is_ascii_uppercase())
+}
+
+fn sha256_file(path: &Path) -> std::io::Result<String> {
+    let bytes = fs::read(path)?;
+    let mut hasher = Sha256::new();
+    hasher.update(&bytes);
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sha256_hex(data: &str) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(data.as_bytes());
+        format!("{:x}", hasher.finalize())
+    }
+
+    /// A fresh, empty cache directory scoped to `name`, so tests don't
+    /// trample each other's cached files.
+    fn scratch_cache_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("pisek-fetch-test-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    /// A fetch command that ignores the URL and writes `payload` to
+    /// `{output}` via the shell, standing in for a real download.
+    fn stub_command(payload: &str) -> Vec<String> {
+        vec![
+            "sh".to_string(),
+            "-c".to_string(),
+            format!("printf '%s' '{payload}' > \"$1\""),
+            "stub".to_string(),
+            "{output}".to_string(),
+        ]
+    }
+
+    #[test]
+    fn is_valid_sha256_accepts_only_64_lowercase_hex_chars() {
+        assert!(is_valid_sha256(&"a".repeat(64)));
+        assert!(is_valid_sha256(&sha256_hex("anything")));
+        assert!(!is_valid_sha256(&"a".repeat(63)));
+        assert!(!is_valid_sha256(&"A".repeat(64)));
+        assert!(!is_valid_sha256("../../etc/passwd"));
+        assert!(!is_valid_sha256(&format!("{}/../../etc/passwd", "a".repeat(64))));
+    }
+
+    #[test]
+    fn resolve_rejects_malformed_checksum_before_touching_the_filesystem() {
+        let cache_dir = scratch_cache_dir("malformed");
+        let cache = FetchCache::new(&cache_dir);
+        let source = FetchSource {
+            url: "https://example.invalid/data".to_string(),
+            sha256: "../../../../tmp/evil".to_string(),
+        };
+        assert!(matches!(
+            cache.resolve(&source),
+            Err(FetchError::InvalidChecksum { .. })
+        ));
+        assert!(!cache_dir.exists());
+    }
+
+    #[test]
+    fn empty_command_is_a_fetch_error_not_a_panic() {
+        let cache_dir = scratch_cache_dir("empty-command");
+        let cache = FetchCache::new(&cache_dir).with_command(vec![]);
+        let source = FetchSource {
+            url: "https://example.invalid/data".to_string(),
+            sha256: sha256_hex("hello"),
+        };
+        assert!(matches!(cache.resolve(&source), Err(FetchError::EmptyCommand)));
+    }
+
+    #[test]
+    fn fetches_and_caches_a_matching_payload() {
+        let cache_dir = scratch_cache_dir("fetch-ok");
+        let cache = FetchCache::new(&cache_dir).with_command(stub_command("hello"));
+        let source = FetchSource {
+            url: "https://example.invalid/data".to_string(),
+            sha256: sha256_hex("hello"),
+        };
+
+        let path = cache.resolve(&source).expect("fetch should succeed");
+        assert_eq!(fs::read_to_string(&path).unwrap(), "hello");
+    }
+
+    #[test]
+    fn checksum_mismatch_is_rejected_and_leaves_no_partial_file() {
+        let cache_dir = scratch_cache_dir("checksum-mismatch");
+        let cache = FetchCache::new(&cache_dir).with_command(stub_command("hello"));
+        let source = FetchSource {
+            url: "https://example.invalid/data".to_string(),
+            sha256: sha256_hex("not-hello"),
+        };
+
+        let err = cache.resolve(&source).unwrap_err();
+        assert!(matches!(err, FetchError::ChecksumMismatch { .. }));
+        assert!(!cache_dir.join(format!("{}.part", source.sha256)).exists());
+    }
+
+    #[test]
+    fn cache_hit_avoids_rerunning_the_fetch_command() {
+        let cache_dir = scratch_cache_dir("cache-hit");
+        let source = FetchSource {
+            url: "https://example.invalid/data".to_string(),
+            sha256: sha256_hex("hello"),
+        };
+
+        let warm = FetchCache::new(&cache_dir).with_command(stub_command("hello"));
+        let path = warm.resolve(&source).expect("first fetch should succeed");
+        assert_eq!(fs::read_to_string(&path).unwrap(), "hello");
+
+        // A command that would fail if it were ever invoked again.
+        let cold = FetchCache::new(&cache_dir).with_command(vec!["false".to_string()]);
+        let path = cold.resolve(&source).expect("cache hit should not run the command");
+        assert_eq!(fs::read_to_string(&path).unwrap(), "hello");
+    }
+
+    #[test]
+    fn corrupted_cache_entry_is_refetched_instead_of_served() {
+        let cache_dir = scratch_cache_dir("corrupted-cache");
+        let source = FetchSource {
+            url: "https://example.invalid/data".to_string(),
+            sha256: sha256_hex("hello"),
+        };
+
+        fs::create_dir_all(&cache_dir).unwrap();
+        fs::write(cache_dir.join(&source.sha256), b"corrupted").unwrap();
+
+        let cache = FetchCache::new(&cache_dir).with_command(stub_command("hello"));
+        let path = cache.resolve(&source).expect("corrupted entry should be refetched");
+        assert_eq!(fs::read_to_string(&path).unwrap(), "hello");
+    }
+}
@@ -0,0 +1,4 @@
+pub mod checker;
+pub mod exec;
+pub mod fetch;
+pub mod report;
@@ -0,0 +1,142 @@
+//! Streaming JSON Lines run report, for CI dashboards and `tail -f` watchers
+//! that want per-test results as soon as they're available rather than
+//! waiting for the whole run to finish.
+//!
+//! Each record is written as one compact JSON object per line (no embedded
+//! newlines), so consumers can split on `\n` and parse each line
+//! independently with `serde_json::from_str`.
+
+use std::io::{self, BufRead, Write};
+
+use serde::{Deserialize, Serialize};
+
+/// A single test's result, emitted as soon as that test finishes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TestRecord {
+    pub solution: String,
+    pub subtask: u32,
+    pub test: String,
+    pub verdict: String,
+    pub time_ms: u64,
+    pub mem_kb: u64,
+    pub exit: i32,
+}
+
+/// Emitted once a solution's run against all test cases has finished.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SummaryRecord {
+    pub solution: String,
+    pub verdict: String,
+    pub tests_run: u32,
+    pub tests_failed: u32,
+}
+
+/// One line of the run report.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ReportEvent {
+    Test(TestRecord),
+    Summary(SummaryRecord),
+}
+
+/// Writes [`ReportEvent`]s as JSON Lines to any [`Write`] sink, flushing
+/// after every line so a `tail -f` watcher sees each test result promptly.
+pub struct JsonlReportWriter<W: Write> {
+    sink: W,
+}
+
+impl<W: Write> JsonlReportWriter<W> {
+    pub fn new(sink: W) -> Self {
+        JsonlReportWriter { sink }
+    }
+
+    /// Serializes `event` as one compact JSON line and flushes it.
+    pub fn write_event(&mut self, event: &ReportEvent) -> io::Result<()> {
+        serde_json::to_writer(&mut self.sink, event)?;
+        self.sink.write_all(b"\n")?;
+        self.sink.flush()
+    }
+}
+
+/// Reads a JSON Lines run report back into [`ReportEvent`]s, one per line.
+pub fn read_events<R: BufRead>(reader: R) -> io::Result<Vec<ReportEvent>> {
+    reader
+        .lines()
+        .map(|line| {
+            let line = line?;
+            serde_json::from_str(&line)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_test(test: &str) -> ReportEvent {
+        ReportEvent::Test(TestRecord {
+            solution: "sol".to_string(),
+            subtask: 1,
+            test: test.to_string(),
+            verdict: "WA".to_string(),
+            time_ms: 120,
+            mem_kb: 4096,
+            exit: 0,
+        })
+    }
+
+    fn sample_summary() -> ReportEvent {
+        ReportEvent::Summary(SummaryRecord {
+            solution: "sol".to_string(),
+            verdict: "WA".to_string(),
+            tests_run: 3,
+            tests_failed: 1,
+        })
+    }
+
+    #[test]
+    fn round_trips_test_and_summary_events() {
+        let events = vec![sample_test("01"), sample_test("02"), sample_summary()];
+
+        let mut buf = Vec::new();
+        {
+            let mut writer = JsonlReportWriter::new(&mut buf);
+            for event in &events {
+                writer.write_event(event).unwrap();
+            }
+        }
+
+        let read_back = read_events(buf.as_slice()).unwrap();
+        assert_eq!(read_back.len(), events.len());
+        for (original, read) in events.iter().zip(&read_back) {
+            assert_eq!(
+                serde_json::to_string(original).unwrap(),
+                serde_json::to_string(read).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn each_event_is_exactly_one_line_with_no_embedded_newlines() {
+        let mut buf = Vec::new();
+        let mut writer = JsonlReportWriter::new(&mut buf);
+        writer.write_event(&sample_test("03")).unwrap();
+        writer.write_event(&sample_summary()).unwrap();
+
+        let text = String::from_utf8(buf).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(text.ends_with('\n'));
+        for line in &lines {
+            assert!(!line.is_empty());
+            assert!(!line.contains('\n'));
+        }
+    }
+
+    #[test]
+    fn read_events_rejects_invalid_json_lines() {
+        let buf = b"not json\n".to_vec();
+        assert!(read_events(buf.as_slice()).is_err());
+    }
+}